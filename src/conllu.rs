@@ -0,0 +1,156 @@
+use crate::{Fact, MyStem, PartOfSpeech, Stemming};
+
+/// Maps a mystem [`PartOfSpeech`](./enum.PartOfSpeech.html) onto its closest
+/// Universal Dependencies UPOS tag.
+fn upos(pos: &PartOfSpeech) -> &'static str {
+    match pos {
+        PartOfSpeech::Adjective => "ADJ",
+        PartOfSpeech::Adverb => "ADV",
+        PartOfSpeech::AdverbPronominal => "ADV",
+        PartOfSpeech::AdjectiveNumeral => "NUM",
+        PartOfSpeech::AdjectivePronoun => "DET",
+        PartOfSpeech::Composite => "X",
+        PartOfSpeech::Conjunction => "CCONJ",
+        PartOfSpeech::Interjection => "INTJ",
+        PartOfSpeech::Numeral => "NUM",
+        PartOfSpeech::Particle => "PART",
+        PartOfSpeech::Preposition => "ADP",
+        PartOfSpeech::Noun => "NOUN",
+        PartOfSpeech::AdjectiveNoun => "PRON",
+        PartOfSpeech::Verb => "VERB",
+    }
+}
+
+/// Reconstructs the mystem part-of-speech abbreviation, for use as part of
+/// the CoNLL-U XPOS column.
+fn pos_tag(pos: &PartOfSpeech) -> &'static str {
+    match pos {
+        PartOfSpeech::Adjective => "A",
+        PartOfSpeech::Adverb => "ADV",
+        PartOfSpeech::AdverbPronominal => "ADVPRO",
+        PartOfSpeech::AdjectiveNumeral => "ANUM",
+        PartOfSpeech::AdjectivePronoun => "APRO",
+        PartOfSpeech::Composite => "COM",
+        PartOfSpeech::Conjunction => "CONJ",
+        PartOfSpeech::Interjection => "INTJ",
+        PartOfSpeech::Numeral => "NUM",
+        PartOfSpeech::Particle => "PART",
+        PartOfSpeech::Preposition => "PR",
+        PartOfSpeech::Noun => "S",
+        PartOfSpeech::AdjectiveNoun => "SPRO",
+        PartOfSpeech::Verb => "V",
+    }
+}
+
+/// Maps a single [`Fact`](./enum.Fact.html) onto its Universal Dependencies
+/// `Key=Value` feature, if one exists. Facts with no UD equivalent (e.g.
+/// [`Fact::Transitivity`](./enum.Fact.html)) return `None` and are left out
+/// of the FEATS column.
+fn ud_feature(fact: &Fact) -> Option<(&'static str, &'static str)> {
+    use crate::Adjective::*;
+    use crate::Animacy::*;
+    use crate::Case::*;
+    use crate::ComparativeDegree::*;
+    use crate::Gender::*;
+    use crate::Mood::*;
+    use crate::PerfectiveAspect::*;
+    use crate::Person::*;
+    use crate::Plurality::*;
+    use crate::Tense::*;
+    use crate::Voice::*;
+    match fact {
+        Fact::Case(Nominative) => Some(("Case", "Nom")),
+        Fact::Case(Genitive) => Some(("Case", "Gen")),
+        Fact::Case(Dative) => Some(("Case", "Dat")),
+        Fact::Case(Accusative) => Some(("Case", "Acc")),
+        Fact::Case(Instrumental) => Some(("Case", "Ins")),
+        Fact::Case(Prepositional) => Some(("Case", "Loc")),
+        Fact::Case(Partitive) => Some(("Case", "Par")),
+        Fact::Case(Locative) => Some(("Case", "Loc")),
+        Fact::Case(Vocative) => Some(("Case", "Voc")),
+        Fact::Tense(Present) => Some(("Tense", "Pres")),
+        Fact::Tense(Inpresent) => Some(("Tense", "Notpast")),
+        Fact::Tense(Past) => Some(("Tense", "Past")),
+        Fact::Plurality(Plural) => Some(("Number", "Plur")),
+        Fact::Plurality(Singular) => Some(("Number", "Sing")),
+        Fact::Mood(Gerunds) => Some(("VerbForm", "Conv")),
+        Fact::Mood(Infinitive) => Some(("VerbForm", "Inf")),
+        Fact::Mood(Participle) => Some(("VerbForm", "Part")),
+        Fact::Mood(Indicative) => Some(("Mood", "Ind")),
+        Fact::Mood(Imperative) => Some(("Mood", "Imp")),
+        Fact::Adjective(Short) => Some(("Variant", "Short")),
+        Fact::Adjective(Long) => Some(("Variant", "Long")),
+        Fact::Adjective(Possessive) => Some(("Poss", "Yes")),
+        Fact::ComparativeDegree(Comparative) => Some(("Degree", "Cmp")),
+        Fact::ComparativeDegree(Superlative) => Some(("Degree", "Sup")),
+        Fact::Person(First) => Some(("Person", "1")),
+        Fact::Person(Second) => Some(("Person", "2")),
+        Fact::Person(Third) => Some(("Person", "3")),
+        Fact::Gender(Masculine) => Some(("Gender", "Masc")),
+        Fact::Gender(Feminine) => Some(("Gender", "Fem")),
+        Fact::Gender(Neuter) => Some(("Gender", "Neut")),
+        Fact::PerfectiveAspect(Perfective) => Some(("Aspect", "Perf")),
+        Fact::PerfectiveAspect(Imperfective) => Some(("Aspect", "Imp")),
+        Fact::Voice(Active) => Some(("Voice", "Act")),
+        Fact::Voice(Passive) => Some(("Voice", "Pass")),
+        Fact::Animacy(Animate) => Some(("Animacy", "Anim")),
+        Fact::Animacy(Inanimate) => Some(("Animacy", "Inan")),
+        Fact::Transitivity(_) => None,
+        Fact::Other(_) => None,
+    }
+}
+
+/// Renders a `Vec` of [`Fact`](./enum.Fact.html)s into a CoNLL-U FEATS
+/// column: alphabetically sorted `Key=Value` pairs joined by `|`, or `_`
+/// if none of the facts have a UD equivalent.
+fn feats(facts: &[Fact]) -> String {
+    let mut pairs: Vec<(&'static str, &'static str)> = facts.iter().filter_map(ud_feature).collect();
+    pairs.sort_unstable();
+    pairs.dedup();
+    if pairs.is_empty() {
+        "_".to_string()
+    } else {
+        pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<String>>()
+            .join("|")
+    }
+}
+
+impl Stemming {
+    /// Renders this token as a single CoNLL-U line, choosing the
+    /// highest-weight [`Lexeme`](./struct.Lexeme.html) as the analysis to
+    /// report. `id` is the 1-based token index within its sentence.
+    pub fn to_conllu(&self, id: usize) -> String {
+        match self.best_lexeme() {
+            Some(lex) => format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t_\t_\t_\t_",
+                id,
+                self.text,
+                lex.lex,
+                upos(&lex.grammem.part_of_speech),
+                pos_tag(&lex.grammem.part_of_speech),
+                feats(&lex.grammem.facts),
+            ),
+            None => format!("{}\t{}\t_\tX\t_\t_\t_\t_\t_\t_", id, self.text),
+        }
+    }
+}
+
+impl MyStem {
+    /// Serializes sentences of [`Stemming`](./struct.Stemming.html)s into a
+    /// full CoNLL-U document, numbering tokens from 1 within each sentence
+    /// and separating sentences with a blank line.
+    pub fn to_conllu(sentences: &[Vec<Stemming>]) -> String {
+        let mut out = String::new();
+        for sentence in sentences {
+            for (i, stemming) in sentence.iter().enumerate() {
+                out.push_str(&stemming.to_conllu(i + 1));
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
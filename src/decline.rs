@@ -0,0 +1,388 @@
+use crate::{AppError, Fact, Gender, MyStem, Other, Stemming};
+
+/// Gender a declension [`Rule`](./struct.Rule.html) applies to. Distinct
+/// from [`Gender`](./enum.Gender.html) because a rule can be androgynous,
+/// i.e. shared by masculine and feminine names alike (most indeclinable
+/// surnames fall in this group).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RuleGender {
+    Masculine,
+    Feminine,
+    Androgynous,
+}
+
+impl RuleGender {
+    fn matches(self, gender: &Gender) -> bool {
+        match self {
+            RuleGender::Androgynous => true,
+            RuleGender::Masculine => *gender == Gender::Masculine,
+            RuleGender::Feminine => *gender == Gender::Feminine,
+        }
+    }
+}
+
+/// A single suffix rule. `test` lists the word endings this rule matches;
+/// `mods` holds the five modifications to apply, in genitive, dative,
+/// accusative, instrumental, prepositional order.
+///
+/// A mod string is applied by counting its leading `-` characters (each one
+/// removes a trailing character from the word) and appending the rest of
+/// the string; `.` leaves the word unchanged.
+struct Rule {
+    gender: RuleGender,
+    test: &'static [&'static str],
+    mods: [&'static str; 5],
+}
+
+struct RuleSet {
+    exceptions: &'static [Rule],
+    suffixes: &'static [Rule],
+}
+
+use RuleGender::{Androgynous, Feminine as Fem, Masculine as Masc};
+
+static FIRSTNAME: RuleSet = RuleSet {
+    exceptions: &[
+        Rule {
+            gender: Fem,
+            test: &["любовь"],
+            mods: ["-и", "-и", ".", "ью", "-и"],
+        },
+        Rule {
+            gender: Masc,
+            test: &["лев"],
+            mods: ["--ьва", "--ьву", "--ьва", "--ьвом", "--ьве"],
+        },
+    ],
+    suffixes: &[
+        Rule {
+            gender: Masc,
+            test: &["й"],
+            mods: ["-я", "-ю", "-я", "-ем", "-е"],
+        },
+        Rule {
+            gender: Masc,
+            test: &["ь"],
+            mods: ["-я", "-ю", "-я", "-ем", "-е"],
+        },
+        Rule {
+            gender: Masc,
+            test: &["а"],
+            mods: ["-ы", "-е", "-у", "-ой", "-е"],
+        },
+        Rule {
+            gender: Masc,
+            test: &["я"],
+            mods: ["-и", "-е", "-ю", "-ей", "-е"],
+        },
+        Rule {
+            gender: Masc,
+            test: &[
+                "б", "в", "г", "д", "ж", "з", "к", "л", "м", "н", "п", "р", "с", "т", "ф", "х",
+                "ц", "ч", "ш", "щ",
+            ],
+            mods: ["а", "у", "а", "ом", "е"],
+        },
+        Rule {
+            gender: Fem,
+            test: &["ия"],
+            mods: ["-и", "-и", "-ю", "-ей", "-и"],
+        },
+        Rule {
+            gender: Fem,
+            test: &["а"],
+            mods: ["-ы", "-е", "-у", "-ой", "-е"],
+        },
+        Rule {
+            gender: Fem,
+            test: &["я"],
+            mods: ["-и", "-е", "-ю", "-ей", "-е"],
+        },
+        Rule {
+            gender: Androgynous,
+            test: &["о", "е", "и", "у", "ю", "ы"],
+            mods: [".", ".", ".", ".", "."],
+        },
+    ],
+};
+
+static PATRONYMIC: RuleSet = RuleSet {
+    exceptions: &[],
+    suffixes: &[
+        Rule {
+            gender: Masc,
+            test: &["ович", "евич", "ич"],
+            mods: ["а", "у", "а", "ем", "е"],
+        },
+        Rule {
+            gender: Fem,
+            test: &["вна", "чна"],
+            mods: ["-ы", "-е", "-у", "-ой", "-е"],
+        },
+    ],
+};
+
+static LASTNAME: RuleSet = RuleSet {
+    exceptions: &[],
+    suffixes: &[
+        Rule {
+            gender: Masc,
+            test: &["ский", "цкий"],
+            mods: ["--ого", "--ому", "--ого", "--им", "--ом"],
+        },
+        Rule {
+            gender: Fem,
+            test: &["ская", "цкая"],
+            mods: ["--ой", "--ой", "--ую", "--ой", "--ой"],
+        },
+        Rule {
+            gender: Masc,
+            test: &["ов", "ев", "ин", "ын"],
+            mods: ["а", "у", "а", "ым", "е"],
+        },
+        Rule {
+            gender: Fem,
+            test: &["ова", "ева", "ина", "ына"],
+            mods: ["-ой", "-ой", "-у", "-ой", "-ой"],
+        },
+        Rule {
+            gender: Androgynous,
+            test: &["о", "е", "и", "у", "ю", "ых", "их"],
+            mods: [".", ".", ".", ".", "."],
+        },
+    ],
+};
+
+/// Applies a single mod string to `word`: leading `-` characters each strip
+/// one trailing character from `word`, and the remainder of the mod is
+/// appended. `.` leaves `word` unchanged.
+fn apply_mod(word: &str, m: &str) -> String {
+    if m == "." {
+        return word.to_string();
+    }
+    let strip = m.chars().take_while(|c| *c == '-').count();
+    let suffix = &m[strip..];
+    let chars: Vec<char> = word.chars().collect();
+    let keep = chars.len().saturating_sub(strip);
+    let mut result: String = chars[..keep].iter().collect();
+    result.push_str(suffix);
+    result
+}
+
+/// Finds the best-matching rule for `word` within `rules`: the one whose
+/// longest matched `test` suffix is the longest overall.
+fn best_rule<'a>(word: &str, gender: &Gender, rules: &'a [Rule]) -> Option<&'a Rule> {
+    let lower = word.to_lowercase();
+    rules
+        .iter()
+        .filter(|r| r.gender.matches(gender))
+        .filter_map(|r| {
+            r.test
+                .iter()
+                .filter(|s| lower.ends_with(*s))
+                .map(|s| s.len())
+                .max()
+                .map(|len| (len, r))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, r)| r)
+}
+
+fn decline_word(word: &str, gender: &Gender, set: &RuleSet) -> [String; 5] {
+    let rule = best_rule(word, gender, set.exceptions).or_else(|| best_rule(word, gender, set.suffixes));
+    match rule {
+        Some(r) => {
+            let mut out: [String; 5] = Default::default();
+            for (i, m) in r.mods.iter().enumerate() {
+                out[i] = apply_mod(word, m);
+            }
+            out
+        }
+        None => {
+            let unchanged = word.to_string();
+            [
+                unchanged.clone(),
+                unchanged.clone(),
+                unchanged.clone(),
+                unchanged.clone(),
+                unchanged,
+            ]
+        }
+    }
+}
+
+enum NameKind {
+    FirstName,
+    Patronymic,
+    LastName,
+}
+
+/// Declines a word already tagged by mystem as a personal name
+/// (`Other::FamilyName`, `Other::Patronymic`, or a proper noun treated as a
+/// first name) into all six Russian cases.
+///
+/// Returns `[nominative, genitive, dative, accusative, instrumental,
+/// prepositional]`, where nominative is simply the original wordform. Kept
+/// as a free function, independent of any running mystem process, so it
+/// can be unit tested directly.
+fn decline_stemming(stemming: &Stemming, gender: Gender) -> Result<[String; 6], AppError> {
+    let name_kind = stemming
+        .lex
+        .iter()
+        .find_map(|lex| {
+            if lex.grammem.facts.contains(&Fact::Other(Other::FamilyName)) {
+                Some(NameKind::LastName)
+            } else if lex.grammem.facts.contains(&Fact::Other(Other::Patronymic)) {
+                Some(NameKind::Patronymic)
+            } else if lex.grammem.facts.contains(&Fact::Other(Other::ProperNoun)) {
+                Some(NameKind::FirstName)
+            } else {
+                None
+            }
+        })
+        .ok_or(AppError::DeclineError(
+            "word is not tagged as a personal name",
+        ))?;
+
+    let set = match name_kind {
+        NameKind::FirstName => &FIRSTNAME,
+        NameKind::Patronymic => &PATRONYMIC,
+        NameKind::LastName => &LASTNAME,
+    };
+    let forms = decline_word(&stemming.text, &gender, set);
+    Ok([
+        stemming.text.clone(),
+        forms[0].clone(),
+        forms[1].clone(),
+        forms[2].clone(),
+        forms[3].clone(),
+        forms[4].clone(),
+    ])
+}
+
+impl MyStem {
+    /// Declines a word already tagged by mystem as a personal name
+    /// (`Other::FamilyName`, `Other::Patronymic`, or a proper noun treated
+    /// as a first name) into all six Russian cases.
+    ///
+    /// Returns `[nominative, genitive, dative, accusative, instrumental,
+    /// prepositional]`, where nominative is simply the original wordform.
+    pub fn decline(&self, stemming: &Stemming, gender: Gender) -> Result<[String; 6], AppError> {
+        decline_stemming(stemming, gender)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(text: &str, other: Other) -> Stemming {
+        Stemming {
+            text: text.to_string(),
+            lex: vec![crate::Lexeme {
+                lex: text.to_lowercase(),
+                grammem: crate::Grammem {
+                    part_of_speech: crate::PartOfSpeech::Noun,
+                    facts: vec![Fact::Other(other)],
+                    facts_raw: vec![],
+                },
+                weight: 1.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn declines_masculine_firstname() {
+        let forms = decline_stemming(&name("Иван", Other::ProperNoun), Gender::Masculine).unwrap();
+        assert_eq!(
+            forms,
+            ["Иван", "Ивана", "Ивану", "Ивана", "Иваном", "Иване"]
+        );
+    }
+
+    #[test]
+    fn declines_feminine_firstname() {
+        let forms = decline_stemming(&name("Анна", Other::ProperNoun), Gender::Feminine).unwrap();
+        assert_eq!(
+            forms,
+            ["Анна", "Анны", "Анне", "Анну", "Анной", "Анне"]
+        );
+    }
+
+    #[test]
+    fn declines_firstname_exception() {
+        let forms = decline_stemming(&name("Лев", Other::ProperNoun), Gender::Masculine).unwrap();
+        assert_eq!(
+            forms,
+            ["Лев", "Льва", "Льву", "Льва", "Львом", "Льве"]
+        );
+    }
+
+    #[test]
+    fn declines_masculine_patronymic() {
+        let forms =
+            decline_stemming(&name("Иванович", Other::Patronymic), Gender::Masculine).unwrap();
+        assert_eq!(
+            forms,
+            [
+                "Иванович",
+                "Ивановича",
+                "Ивановичу",
+                "Ивановича",
+                "Ивановичем",
+                "Ивановиче"
+            ]
+        );
+    }
+
+    #[test]
+    fn declines_masculine_surname() {
+        let forms =
+            decline_stemming(&name("Иванов", Other::FamilyName), Gender::Masculine).unwrap();
+        assert_eq!(
+            forms,
+            [
+                "Иванов",
+                "Иванова",
+                "Иванову",
+                "Иванова",
+                "Ивановым",
+                "Иванове"
+            ]
+        );
+    }
+
+    #[test]
+    fn declines_adjective_type_surname() {
+        let forms =
+            decline_stemming(&name("Достоевский", Other::FamilyName), Gender::Masculine).unwrap();
+        assert_eq!(
+            forms,
+            [
+                "Достоевский",
+                "Достоевского",
+                "Достоевскому",
+                "Достоевского",
+                "Достоевским",
+                "Достоевском"
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_word_not_tagged_as_a_name() {
+        let word = Stemming {
+            text: "стол".to_string(),
+            lex: vec![crate::Lexeme {
+                lex: "стол".to_string(),
+                grammem: crate::Grammem {
+                    part_of_speech: crate::PartOfSpeech::Noun,
+                    facts: vec![],
+                    facts_raw: vec![],
+                },
+                weight: 1.0,
+            }],
+        };
+        assert!(decline_stemming(&word, Gender::Masculine).is_err());
+    }
+}
@@ -7,6 +7,7 @@ pub enum AppError {
     GrammemError(&'static str),
     PopenError(PopenError),
     MystemError(&'static str),
+    DeclineError(&'static str),
 }
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -6,8 +6,9 @@ use crate::ComparativeDegree::{Comparative, Superlative};
 use crate::Gender::{Feminine, Masculine, Neuter};
 use crate::Mood::{Gerunds, Imperative, Indicative, Infinitive, Participle};
 use crate::Other::{
-    Abbreviation, Awkward, CommonForm, Distorted, FamilyName, Geo, Informal, Obscene, Obsolete,
-    Parenthesis, Patronymic, Predicative, ProperNoun, Rare,
+    Abbreviation, Awkward, CommonForm, Distorted, FamilyName, Figurative, Geo, Historical,
+    Informal, Literary, LowColloquial, Obscene, Obsolete, Parenthesis, Patronymic, Poetic,
+    Predicative, ProperNoun, Rare,
 };
 use crate::PerfectiveAspect::{Imperfective, Perfective};
 use crate::Plurality::{Plural, Singular};
@@ -291,6 +292,16 @@ pub enum Other {
     Obsolete,
     /// Фамилия
     FamilyName,
+    /// Переносное значение
+    Figurative,
+    /// Историзм
+    Historical,
+    /// Поэтическая форма
+    Poetic,
+    /// Литературная форма
+    Literary,
+    /// Просторечная форма
+    LowColloquial,
 }
 
 impl FromStr for Fact {
@@ -349,6 +360,11 @@ impl FromStr for Fact {
             "abbr" => Ok(Fact::Other(Abbreviation)),
             "obsol" => Ok(Fact::Other(Obsolete)),
             "famn" => Ok(Fact::Other(FamilyName)),
+            "peren" => Ok(Fact::Other(Figurative)),
+            "hist" => Ok(Fact::Other(Historical)),
+            "poet" => Ok(Fact::Other(Poetic)),
+            "lit" => Ok(Fact::Other(Literary)),
+            "prost" => Ok(Fact::Other(LowColloquial)),
             //_ => Ok(Fact::Case(Vocative)),
             _ => Err(AppError::GrammemError("Failed to get Grammem.")),
         }
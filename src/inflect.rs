@@ -0,0 +1,363 @@
+use crate::{Gender, PartOfSpeech};
+use std::collections::HashMap;
+
+/// A full inflection table for a lemma, keyed by morphological "slot"
+/// (e.g. `nom_sg`/`gen_pl` for nouns, `nom_sg_f`/`gen_pl` for adjectives,
+/// `praes_1p_sg`/`inf` for verbs). Each slot holds zero or more surface
+/// forms, since some slots are genuinely ambiguous or unattested.
+#[derive(Debug)]
+pub struct Paradigm {
+    pub lemma: String,
+    pub pos: PartOfSpeech,
+    pub slots: HashMap<String, Vec<String>>,
+}
+
+impl Paradigm {
+    fn empty(lemma: &str, pos: PartOfSpeech) -> Self {
+        Paradigm {
+            lemma: lemma.to_string(),
+            pos,
+            slots: HashMap::new(),
+        }
+    }
+
+    fn fill(mut self, forms: &[(&str, &str)]) -> Self {
+        for (slot, form) in forms {
+            self.slots.insert(slot.to_string(), vec![form.to_string()]);
+        }
+        self
+    }
+
+    /// The forms registered for `slot`, or an empty slice if it was never
+    /// populated.
+    pub fn slot(&self, slot: &str) -> &[String] {
+        self.slots.get(slot).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+/// Builds a noun paradigm from explicit, caller-supplied forms. Use this
+/// for irregular nouns (e.g. `путь`, `мать`) that don't fit any of the
+/// regular declension classes `inflect` recognizes.
+pub fn mk_noun(lemma: &str, forms: &[(&str, &str)]) -> Paradigm {
+    Paradigm::empty(lemma, PartOfSpeech::Noun).fill(forms)
+}
+
+/// Builds an adjective paradigm from explicit, caller-supplied forms. Use
+/// this for irregular adjectives (e.g. comparative-only or suppletive
+/// forms like `хороший`/`лучше`) that the regular hard/soft patterns
+/// can't produce.
+pub fn mk_adj(lemma: &str, forms: &[(&str, &str)]) -> Paradigm {
+    Paradigm::empty(lemma, PartOfSpeech::Adjective).fill(forms)
+}
+
+/// Builds a verb paradigm from explicit, caller-supplied forms. Use this
+/// for irregular verbs (e.g. `быть`, `хотеть`) that the regular
+/// `-ать`/`-ить` conjugation patterns can't produce.
+pub fn mk_verb(lemma: &str, forms: &[(&str, &str)]) -> Paradigm {
+    Paradigm::empty(lemma, PartOfSpeech::Verb).fill(forms)
+}
+
+/// Russian spelling rule: `ы` never follows г/к/х/ж/ч/ш/щ — `и` is used
+/// instead. Declension endings that start with `ы` must respect this.
+fn y_or_i(stem: &str) -> &'static str {
+    match stem.chars().last() {
+        Some('г' | 'к' | 'х' | 'ж' | 'ч' | 'ш' | 'щ') => "и",
+        _ => "ы",
+    }
+}
+
+/// Regular noun declension classes, selected by the lemma's final
+/// vowel/consonant, in the style of a school-grammar declension table.
+///
+/// A lemma ending in `ь` is ambiguous between the masculine 2nd declension
+/// (e.g. `словарь`) and the feminine 3rd declension (e.g. `ночь`), which
+/// take entirely different endings — `gender` disambiguates which one to
+/// use. `None` is returned for `ь`-final lemmas when `gender` isn't
+/// supplied, or when it's `Neuter` (Russian has essentially no neuter
+/// nouns ending in `ь`, so there's no pattern to guess), rather than
+/// silently guessing.
+fn inflect_noun(lemma: &str, gender: Option<Gender>) -> Option<Paradigm> {
+    if let Some(stem) = lemma.strip_suffix("а").or_else(|| lemma.strip_suffix("я")) {
+        let y = y_or_i(stem);
+        return Some(Paradigm::empty(lemma, PartOfSpeech::Noun).fill(&[
+            ("nom_sg", lemma),
+            ("gen_sg", &format!("{}{}", stem, y)),
+            ("dat_sg", &format!("{}е", stem)),
+            ("acc_sg", &format!("{}у", stem)),
+            ("ins_sg", &format!("{}ой", stem)),
+            ("prep_sg", &format!("{}е", stem)),
+            ("nom_pl", &format!("{}{}", stem, y)),
+            ("gen_pl", stem),
+            ("dat_pl", &format!("{}ам", stem)),
+            ("acc_pl", &format!("{}{}", stem, y)),
+            ("ins_pl", &format!("{}ами", stem)),
+            ("prep_pl", &format!("{}ах", stem)),
+        ]));
+    }
+    if let Some(stem) = lemma.strip_suffix("о").or_else(|| lemma.strip_suffix("е")) {
+        return Some(Paradigm::empty(lemma, PartOfSpeech::Noun).fill(&[
+            ("nom_sg", lemma),
+            ("gen_sg", &format!("{}а", stem)),
+            ("dat_sg", &format!("{}у", stem)),
+            ("acc_sg", lemma),
+            ("ins_sg", &format!("{}ом", stem)),
+            ("prep_sg", &format!("{}е", stem)),
+            ("nom_pl", &format!("{}а", stem)),
+            ("gen_pl", stem),
+            ("dat_pl", &format!("{}ам", stem)),
+            ("acc_pl", &format!("{}а", stem)),
+            ("ins_pl", &format!("{}ами", stem)),
+            ("prep_pl", &format!("{}ах", stem)),
+        ]));
+    }
+    if let Some(stem) = lemma.strip_suffix("ь") {
+        return match gender? {
+            Gender::Masculine => Some(Paradigm::empty(lemma, PartOfSpeech::Noun).fill(&[
+                ("nom_sg", lemma),
+                ("gen_sg", &format!("{}я", stem)),
+                ("dat_sg", &format!("{}ю", stem)),
+                ("acc_sg", lemma),
+                ("ins_sg", &format!("{}ем", stem)),
+                ("prep_sg", &format!("{}е", stem)),
+                ("nom_pl", &format!("{}и", stem)),
+                ("gen_pl", &format!("{}ей", stem)),
+                ("dat_pl", &format!("{}ям", stem)),
+                ("acc_pl", &format!("{}и", stem)),
+                ("ins_pl", &format!("{}ями", stem)),
+                ("prep_pl", &format!("{}ях", stem)),
+            ])),
+            Gender::Neuter => None,
+            Gender::Feminine => {
+                Some(Paradigm::empty(lemma, PartOfSpeech::Noun).fill(&[
+                    ("nom_sg", lemma),
+                    ("gen_sg", &format!("{}и", stem)),
+                    ("dat_sg", &format!("{}и", stem)),
+                    ("acc_sg", lemma),
+                    ("ins_sg", &format!("{}ью", stem)),
+                    ("prep_sg", &format!("{}и", stem)),
+                    ("nom_pl", &format!("{}и", stem)),
+                    ("gen_pl", &format!("{}ей", stem)),
+                    ("dat_pl", &format!("{}ям", stem)),
+                    ("acc_pl", &format!("{}и", stem)),
+                    ("ins_pl", &format!("{}ями", stem)),
+                    ("prep_pl", &format!("{}ях", stem)),
+                ]))
+            }
+        };
+    }
+    // Falls through to the hard-consonant masculine declension, the most
+    // common class and the safest default.
+    let y = y_or_i(lemma);
+    Some(Paradigm::empty(lemma, PartOfSpeech::Noun).fill(&[
+        ("nom_sg", lemma),
+        ("gen_sg", &format!("{}а", lemma)),
+        ("dat_sg", &format!("{}у", lemma)),
+        ("acc_sg", lemma),
+        ("ins_sg", &format!("{}ом", lemma)),
+        ("prep_sg", &format!("{}е", lemma)),
+        ("nom_pl", &format!("{}{}", lemma, y)),
+        ("gen_pl", &format!("{}ов", lemma)),
+        ("dat_pl", &format!("{}ам", lemma)),
+        ("acc_pl", &format!("{}{}", lemma, y)),
+        ("ins_pl", &format!("{}ами", lemma)),
+        ("prep_pl", &format!("{}ах", lemma)),
+    ]))
+}
+
+/// Regular adjective declension: the hard pattern (`-ый`/`-ой`) and the
+/// soft pattern (`-ий`), each producing the full case/gender/number table
+/// plus the short forms.
+fn inflect_adjective(lemma: &str) -> Option<Paradigm> {
+    let (stem, a, o, y, i) = if let Some(stem) = lemma.strip_suffix("ий") {
+        (stem, "яя", "ее", "ие", "и")
+    } else if let Some(stem) = lemma.strip_suffix("ый").or_else(|| lemma.strip_suffix("ой")) {
+        (stem, "ая", "ое", "ые", "ы")
+    } else {
+        return None;
+    };
+    Some(Paradigm::empty(lemma, PartOfSpeech::Adjective).fill(&[
+        ("nom_sg_m", lemma),
+        ("nom_sg_f", &format!("{}{}", stem, a)),
+        ("nom_sg_n", &format!("{}{}", stem, o)),
+        ("nom_pl", &format!("{}{}", stem, y)),
+        ("gen_sg_m", &format!("{}ого", stem)),
+        ("gen_sg_n", &format!("{}ого", stem)),
+        ("gen_sg_f", &format!("{}ой", stem)),
+        ("gen_pl", &format!("{}{}х", stem, i)),
+        ("dat_sg_m", &format!("{}ому", stem)),
+        ("dat_sg_n", &format!("{}ому", stem)),
+        ("dat_sg_f", &format!("{}ой", stem)),
+        ("dat_pl", &format!("{}{}м", stem, i)),
+        ("acc_sg_f", &format!("{}ую", stem)),
+        ("acc_sg_n", &format!("{}{}", stem, o)),
+        ("ins_sg_m", &format!("{}{}м", stem, y)),
+        ("ins_sg_n", &format!("{}{}м", stem, y)),
+        ("ins_sg_f", &format!("{}ой", stem)),
+        ("ins_pl", &format!("{}{}ми", stem, i)),
+        ("prep_sg_m", &format!("{}ом", stem)),
+        ("prep_sg_n", &format!("{}ом", stem)),
+        ("prep_sg_f", &format!("{}ой", stem)),
+        ("prep_pl", &format!("{}{}х", stem, i)),
+        ("short_m", stem),
+        ("short_f", &format!("{}а", stem)),
+        ("short_n", &format!("{}о", stem)),
+        ("short_pl", &format!("{}{}", stem, i)),
+    ]))
+}
+
+/// Regular verb conjugation: the `-ать` (1st conjugation) and `-ить` (2nd
+/// conjugation) patterns, covering present/future, past, imperative,
+/// gerund and participle slots.
+fn inflect_verb(lemma: &str) -> Option<Paradigm> {
+    let past_stem = lemma.strip_suffix("ть")?;
+    let (stem, sg2, sg3, pl1, pl2, pl3, imper_sg, act, pass) = if lemma.ends_with("ать") {
+        // The thematic vowel `а` stays in the stem (`читать` -> `чита`):
+        // the present-tense endings below attach directly after it.
+        (
+            past_stem, "ешь", "ет", "ем", "ете", "ют", "й", "ющий", "емый",
+        )
+    } else if let Some(stem) = lemma.strip_suffix("ить") {
+        (stem, "ишь", "ит", "им", "ите", "ят", "и", "ящий", "имый")
+    } else {
+        return None;
+    };
+    Some(Paradigm::empty(lemma, PartOfSpeech::Verb).fill(&[
+        ("inf", lemma),
+        ("praes_1p_sg", &format!("{}ю", stem)),
+        ("praes_2p_sg", &format!("{}{}", stem, sg2)),
+        ("praes_3p_sg", &format!("{}{}", stem, sg3)),
+        ("praes_1p_pl", &format!("{}{}", stem, pl1)),
+        ("praes_2p_pl", &format!("{}{}", stem, pl2)),
+        ("praes_3p_pl", &format!("{}{}", stem, pl3)),
+        ("praet_m", &format!("{}л", past_stem)),
+        ("praet_f", &format!("{}ла", past_stem)),
+        ("praet_n", &format!("{}ло", past_stem)),
+        ("praet_pl", &format!("{}ли", past_stem)),
+        ("imper_sg", &format!("{}{}", stem, imper_sg)),
+        ("imper_pl", &format!("{}{}те", stem, imper_sg)),
+        ("ger", &format!("{}я", stem)),
+        ("partcp_act", &format!("{}{}", stem, act)),
+        ("partcp_pass", &format!("{}{}", stem, pass)),
+    ]))
+}
+
+/// Generates the full inflection table for `lemma`, picking a regular
+/// declension/conjugation pattern by `pos` and the lemma's ending.
+/// `gender` is only consulted for nouns ending in `ь`, where it
+/// disambiguates the masculine 2nd declension from the feminine 3rd
+/// declension (see [`inflect_noun`]'s docs); pass `None` when it isn't
+/// known and such lemmas should come back empty rather than guessed.
+/// Returns `None` when no regular pattern matches, in which case callers
+/// should build the paradigm explicitly with [`mk_noun`], [`mk_adj`] or
+/// [`mk_verb`].
+pub fn inflect(lemma: &str, pos: PartOfSpeech, gender: Option<Gender>) -> Option<Paradigm> {
+    match pos {
+        PartOfSpeech::Noun | PartOfSpeech::AdjectiveNoun => inflect_noun(lemma, gender),
+        PartOfSpeech::Adjective => inflect_adjective(lemma),
+        PartOfSpeech::Verb => inflect_verb(lemma),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noun_feminine_applies_spelling_rule() {
+        let p = inflect("книга", PartOfSpeech::Noun, None).unwrap();
+        assert_eq!(p.slot("nom_pl"), ["книги"]);
+        assert_eq!(p.slot("gen_sg"), ["книги"]);
+        assert_eq!(p.slot("acc_pl"), ["книги"]);
+    }
+
+    #[test]
+    fn noun_masculine_applies_spelling_rule() {
+        let p = inflect("урок", PartOfSpeech::Noun, None).unwrap();
+        assert_eq!(p.slot("nom_pl"), ["уроки"]);
+    }
+
+    #[test]
+    fn noun_masculine_default_keeps_y() {
+        let p = inflect("стол", PartOfSpeech::Noun, None).unwrap();
+        assert_eq!(p.slot("nom_pl"), ["столы"]);
+        assert_eq!(p.slot("gen_sg"), ["стола"]);
+    }
+
+    #[test]
+    fn noun_soft_sign_masculine_uses_2nd_declension() {
+        let p = inflect("словарь", PartOfSpeech::Noun, Some(Gender::Masculine)).unwrap();
+        assert_eq!(p.slot("gen_sg"), ["словаря"]);
+        assert_eq!(p.slot("dat_sg"), ["словарю"]);
+        assert_eq!(p.slot("ins_sg"), ["словарем"]);
+        assert_eq!(p.slot("nom_pl"), ["словари"]);
+        assert_eq!(p.slot("gen_pl"), ["словарей"]);
+    }
+
+    #[test]
+    fn noun_soft_sign_neuter_is_none() {
+        assert!(inflect("словарь", PartOfSpeech::Noun, Some(Gender::Neuter)).is_none());
+    }
+
+    #[test]
+    fn noun_soft_sign_feminine_uses_3rd_declension() {
+        let p = inflect("ночь", PartOfSpeech::Noun, Some(Gender::Feminine)).unwrap();
+        assert_eq!(p.slot("gen_sg"), ["ночи"]);
+        assert_eq!(p.slot("dat_sg"), ["ночи"]);
+        assert_eq!(p.slot("ins_sg"), ["ночью"]);
+    }
+
+    #[test]
+    fn noun_soft_sign_without_gender_is_none() {
+        assert!(inflect("словарь", PartOfSpeech::Noun, None).is_none());
+    }
+
+    #[test]
+    fn adjective_hard_uses_oblique_vowel_in_dat_ins_short_pl() {
+        let p = inflect("новый", PartOfSpeech::Adjective, None).unwrap();
+        assert_eq!(p.slot("dat_pl"), ["новым"]);
+        assert_eq!(p.slot("ins_pl"), ["новыми"]);
+        assert_eq!(p.slot("short_pl"), ["новы"]);
+    }
+
+    #[test]
+    fn adjective_soft_uses_oblique_vowel_in_dat_ins_short_pl() {
+        let p = inflect("синий", PartOfSpeech::Adjective, None).unwrap();
+        assert_eq!(p.slot("dat_pl"), ["синим"]);
+        assert_eq!(p.slot("ins_pl"), ["синими"]);
+        assert_eq!(p.slot("short_pl"), ["сини"]);
+    }
+
+    #[test]
+    fn verb_at_conjugation_keeps_thematic_vowel() {
+        let p = inflect("читать", PartOfSpeech::Verb, None).unwrap();
+        assert_eq!(p.slot("praes_1p_sg"), ["читаю"]);
+        assert_eq!(p.slot("praes_2p_sg"), ["читаешь"]);
+        assert_eq!(p.slot("imper_sg"), ["читай"]);
+        assert_eq!(p.slot("partcp_act"), ["читающий"]);
+    }
+
+    #[test]
+    fn verb_it_conjugation() {
+        let p = inflect("говорить", PartOfSpeech::Verb, None).unwrap();
+        assert_eq!(p.slot("praes_3p_sg"), ["говорит"]);
+    }
+
+    #[test]
+    fn inflect_returns_none_for_unrecognized_pattern() {
+        assert!(inflect("вчера", PartOfSpeech::Adverb, None).is_none());
+    }
+
+    #[test]
+    fn mk_helpers_build_explicit_paradigms() {
+        let p = mk_noun("путь", &[("nom_sg", "путь"), ("gen_sg", "пути")]);
+        assert_eq!(p.slot("gen_sg"), ["пути"]);
+        assert_eq!(p.slot("dat_sg"), [] as [String; 0]);
+
+        let p = mk_adj("хороший", &[("comp", "лучше")]);
+        assert_eq!(p.slot("comp"), ["лучше"]);
+
+        let p = mk_verb("быть", &[("praes_3p_sg", "есть")]);
+        assert_eq!(p.slot("praes_3p_sg"), ["есть"]);
+    }
+}
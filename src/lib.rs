@@ -1,6 +1,10 @@
 #![crate_name = "mystem"]
+mod conllu;
+mod decline;
 mod error;
 mod grammems;
+pub mod inflect;
+mod register;
 
 use serde_json::Value;
 use std::io::{prelude::*, BufReader, Error, Write};
@@ -16,6 +20,9 @@ pub use grammems::*;
 #[derive(Debug)]
 pub struct MyStem {
     pub process: Popen,
+    /// Whether `-d` (contextual disambiguation) was requested, so the
+    /// process can be restarted with the same flags if it dies.
+    disambiguate: bool,
 }
 
 /// Lexeme struct
@@ -38,19 +45,54 @@ pub struct Stemming {
     pub lex: Vec<Lexeme>,
 }
 
+impl Stemming {
+    /// Returns the single highest-`weight` [`Lexeme`](./struct.Lexeme.html),
+    /// i.e. the most likely analysis, instead of forcing callers to assume
+    /// `lex[0]` is the best reading. Ties are broken deterministically in
+    /// favor of the earliest-listed lexeme.
+    pub fn best_lexeme(&self) -> Option<&Lexeme> {
+        let mut best: Option<&Lexeme> = None;
+        for lex in &self.lex {
+            best = match best {
+                Some(b) if lex.weight <= b.weight => Some(b),
+                _ => Some(lex),
+            };
+        }
+        best
+    }
+}
+
 impl MyStem {
     /// Returns a MyStem instance with running process
     /// of mystem binary. It keeps mystem running all the time
     /// and reuse it.
     pub fn new() -> Result<Self, AppError> {
-        let p = MyStem::open_process()?;
+        MyStem::with_options(false)
+    }
+
+    /// Returns a MyStem instance with mystem's contextual disambiguation
+    /// (`-d`) enabled, so that homonymous analyses are pre-ranked using
+    /// surrounding context instead of left in mystem's default order.
+    pub fn with_disambiguation() -> Result<Self, AppError> {
+        MyStem::with_options(true)
+    }
+
+    fn with_options(disambiguate: bool) -> Result<Self, AppError> {
+        let p = MyStem::open_process(disambiguate)?;
         debug!("Mystem started with PID {}", p.pid().unwrap());
-        Ok(Self { process: p })
+        Ok(Self {
+            process: p,
+            disambiguate,
+        })
     }
 
-    fn open_process() -> Result<Popen, PopenError> {
+    fn open_process(disambiguate: bool) -> Result<Popen, PopenError> {
+        let mut args = vec!["mystem", "-i", "--format", "json", "--eng-gr", "--weight"];
+        if disambiguate {
+            args.push("-d");
+        }
         Popen::create(
-            &["mystem", "-i", "--format", "json", "--eng-gr", "--weight"],
+            &args,
             PopenConfig {
                 stdout: Redirection::Pipe,
                 stdin: Redirection::Pipe,
@@ -106,7 +148,7 @@ impl MyStem {
                 self.process.pid().unwrap(),
                 exit_status
             );
-            self.process = MyStem::open_process()?;
+            self.process = MyStem::open_process(self.disambiguate)?;
         }
         let mut clean_text = text.trim().to_string();
         for c in clean_text.clone().chars() {
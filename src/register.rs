@@ -0,0 +1,20 @@
+use crate::{Fact, Lexeme, Other, Stemming};
+
+impl Stemming {
+    /// Returns the [`Lexeme`](./struct.Lexeme.html)s of this `Stemming`
+    /// whose stylistic register facts (e.g. `Other::Obscene`,
+    /// `Other::LowColloquial`) don't appear in `exclude`. Pass an empty
+    /// slice to keep every reading, or e.g. `&[Other::Obscene]` to drop
+    /// obscene-register readings and keep only neutral ones.
+    pub fn filter_by_register(&self, exclude: &[Other]) -> Vec<&Lexeme> {
+        self.lex
+            .iter()
+            .filter(|lex| {
+                !lex.grammem
+                    .facts
+                    .iter()
+                    .any(|f| matches!(f, Fact::Other(o) if exclude.contains(o)))
+            })
+            .collect()
+    }
+}